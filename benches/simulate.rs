@@ -5,6 +5,7 @@ use criterion::criterion_group;
 use criterion::criterion_main;
 use life_universe_everything::simulator::Age;
 use life_universe_everything::simulator::Parameters;
+use life_universe_everything::simulator::default_migrant_age_profile;
 
 pub fn benchmark(c: &mut Criterion) {
 	let parameters = black_box(Parameters {
@@ -14,6 +15,9 @@ pub fn benchmark(c: &mut Criterion) {
 		males_per_100_females: 105,
 		target_total_fertility_rate: 2.0802,
 		infant_mortality_rate: 0.0050,
+		net_migration_per_year: 0,
+		migrant_age_profile: default_migrant_age_profile,
+		parity_fertility: None,
 	});
 	c.bench_function("simulate", |b| {
 		b.iter(|| {
@@ -9,18 +9,42 @@ use eframe::egui::ScrollArea;
 use eframe::egui::SliderClamping;
 use egui_plot::Bar;
 use egui_plot::BarChart;
+use egui_plot::Line;
 use egui_plot::Plot;
+use egui_plot::PlotPoints;
 
-use crate::optimizer::solve;
+use crate::microsim::MAX_MICRO_POPULATION;
+use crate::microsim::MicroParameters;
+use crate::microsim::MicroSimulationResult;
+use crate::optimizer::CalibrationObjective;
+use crate::optimizer::GaSettings;
+use crate::optimizer::Lever;
+use crate::optimizer::calibrate;
+use crate::optimizer::solve_with_lever;
 use crate::simulator::Age;
+use crate::simulator::Count;
 use crate::simulator::Parameters;
+use crate::simulator::ParityFertilityParameters;
 use crate::simulator::SimulationResult;
+use crate::simulator::default_migrant_age_profile;
 
 pub struct MyApp {
 	parameters: Parameters,
 	solution: SimulationResult,
 	original_parameters: Parameters,
 	out_file: String,
+	lever: Lever,
+	calibration_objective: CalibrationObjective,
+	/// Agent-level population for the microsim panel; kept separate from
+	/// `parameters.initial_population`, which is far too large for that
+	/// engine to track individually.
+	micro_initial_population: Count,
+	micro_seed: u64,
+	micro_replications: u32,
+	micro_result: Option<MicroSimulationResult>,
+	/// Set while a background microsim run is in flight; polled in
+	/// `update` and cleared once the result arrives.
+	micro_running: Option<std::sync::mpsc::Receiver<MicroSimulationResult>>,
 }
 
 impl Default for MyApp {
@@ -32,8 +56,11 @@ impl Default for MyApp {
 			males_per_100_females: 105,
 			target_total_fertility_rate: 2.06406,
 			infant_mortality_rate: 0.005,
+			net_migration_per_year: 0,
+			migrant_age_profile: default_migrant_age_profile,
+			parity_fertility: None,
 		};
-		let parameters = solve(parameters);
+		let parameters = solve_with_lever(parameters, Lever::TotalFertilityRate);
 		let solution = parameters.run();
 		Self {
 			// let sr = run(10_000_000_000, Year(2_000), 1_000, Age(120), 105, 2.06406);
@@ -41,12 +68,29 @@ impl Default for MyApp {
 			solution,
 			original_parameters: parameters,
 			out_file: String::from("data.json5"),
+			lever: Lever::TotalFertilityRate,
+			calibration_objective: CalibrationObjective::StableSlope,
+			micro_initial_population: 2_000,
+			micro_seed: 0,
+			micro_replications: 20,
+			micro_result: None,
+			micro_running: None,
 		}
 	}
 }
 
 impl eframe::App for MyApp {
 	fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+		if let Some(receiver) = &self.micro_running {
+			if let Ok(result) = receiver.try_recv() {
+				self.micro_result = Some(result);
+				self.micro_running = None;
+			}
+		}
+		if self.micro_running.is_some() {
+			ctx.request_repaint();
+		}
+
 		let prev_params = self.parameters;
 		egui::TopBottomPanel::top("top").show(ctx, |ui| {
 			ui.heading("Life, the Universe and Everything");
@@ -99,8 +143,94 @@ impl eframe::App for MyApp {
 							0.0..=3.0,
 						));
 						ui.label("target fertility rate");
+						ui.end_row();
+
+						ui.add(egui::Slider::new(
+							&mut self.parameters.net_migration_per_year,
+							-1_000_000..=1_000_000,
+						));
+						ui.label("net migration per year");
+						ui.end_row();
+
+						let mut parity_fertility_enabled = self.parameters.parity_fertility.is_some();
+						if ui
+							.checkbox(&mut parity_fertility_enabled, "parity-structured fertility")
+							.changed()
+						{
+							self.parameters.parity_fertility = if parity_fertility_enabled {
+								Some(ParityFertilityParameters {
+									childlessness_fraction: 0.15,
+									align_to_target_tfr: true,
+								})
+							} else {
+								None
+							};
+						}
+						ui.end_row();
+						if let Some(parity_fertility) = &mut self.parameters.parity_fertility {
+							ui.add(egui::Slider::new(
+								&mut parity_fertility.childlessness_fraction,
+								0.0..=1.0,
+							));
+							ui.label("childlessness fraction");
+							ui.end_row();
+							ui.checkbox(&mut parity_fertility.align_to_target_tfr, "align to target TFR");
+							ui.end_row();
+						}
+
+						ui.horizontal(|ui| {
+							ui.selectable_value(
+								&mut self.lever,
+								Lever::TotalFertilityRate,
+								"fertility",
+							);
+							ui.selectable_value(&mut self.lever, Lever::NetMigration, "migration");
+						});
 						if ui.button("stabilize").clicked() {
-							let parameters = solve(self.parameters);
+							let parameters = solve_with_lever(self.parameters, self.lever);
+							self.parameters = parameters;
+						}
+						ui.end_row();
+
+						ui.horizontal(|ui| {
+							ui.selectable_value(
+								&mut self.calibration_objective,
+								CalibrationObjective::StableSlope,
+								"stable slope",
+							);
+							let is_target_population = matches!(
+								self.calibration_objective,
+								CalibrationObjective::TargetPopulation { .. }
+							);
+							if ui
+								.selectable_label(is_target_population, "target population")
+								.clicked()
+							{
+								self.calibration_objective = CalibrationObjective::TargetPopulation {
+									target_final_population: self.parameters.initial_population,
+								};
+							}
+						});
+						if let CalibrationObjective::TargetPopulation {
+							target_final_population,
+						} = &mut self.calibration_objective
+						{
+							ui.add(
+								egui::Slider::new(
+									target_final_population,
+									10_u64.pow(3)..=10_u64.pow(13),
+								)
+								.logarithmic(true),
+							);
+							ui.label("target final population");
+							ui.end_row();
+						}
+						if ui.button("calibrate").clicked() {
+							let parameters = calibrate(
+								self.parameters,
+								self.calibration_objective,
+								GaSettings::default(),
+							);
 							self.parameters = parameters;
 						}
 						ui.end_row();
@@ -128,6 +258,15 @@ impl eframe::App for MyApp {
 						ui.label("Actual fertility");
 						ui.label(format!("{:.3}", self.solution.cohort_fertility.avg()));
 						ui.end_row();
+						ui.label("Total net migration");
+						ui.label(format!("{}", self.solution.total_net_migration));
+						ui.end_row();
+						ui.label("Life expectancy at birth");
+						ui.label(format!("{:.1}", self.solution.life_expectancy_at_birth));
+						ui.end_row();
+						ui.label("Crude death rate");
+						ui.label(format!("{:.2} / 1,000", self.solution.crude_death_rate));
+						ui.end_row();
 					});
 			});
 		});
@@ -191,6 +330,95 @@ impl eframe::App for MyApp {
 							ui.bar_chart(BarChart::new("bc2", bars));
 						});
 				});
+				ui.group(|ui| {
+					ui.heading("Net migration over time");
+					Plot::new("migration")
+						.show_grid([false, false])
+						.height(150.0)
+						.show(ui, |ui| {
+							let bars = self
+								.solution
+								.timeline
+								.net_migration
+								.iter()
+								.map(|(&year, &net_migration)| {
+									Bar::new(year.0 as f64, net_migration as f64)
+										.fill(Color32::BLUE)
+								})
+								.collect();
+							ui.bar_chart(BarChart::new("bc3", bars));
+						});
+				});
+				ui.group(|ui| {
+					ui.heading("Microsimulation");
+					ui.add(
+						egui::Slider::new(&mut self.micro_initial_population, 100..=MAX_MICRO_POPULATION)
+							.logarithmic(true),
+					);
+					ui.label("micro population");
+					ui.horizontal(|ui| {
+						ui.add(egui::Slider::new(&mut self.micro_replications, 1..=200));
+						ui.label("replications");
+					});
+					ui.horizontal(|ui| {
+						ui.add(egui::DragValue::new(&mut self.micro_seed));
+						ui.label("seed");
+					});
+					let running = self.micro_running.is_some();
+					ui.add_enabled_ui(!running, |ui| {
+						if ui.button("run microsimulation").clicked() {
+							let micro_parameters = MicroParameters {
+								parameters: self.parameters,
+								initial_population: self.micro_initial_population,
+								seed: self.micro_seed,
+							};
+							let replications = self.micro_replications;
+							#[cfg(not(target_arch = "wasm32"))]
+							{
+								let (sender, receiver) = std::sync::mpsc::channel();
+								std::thread::spawn(move || {
+									let _ = sender.send(micro_parameters.run_micro(replications));
+								});
+								self.micro_running = Some(receiver);
+							}
+							#[cfg(target_arch = "wasm32")]
+							{
+								self.micro_result = Some(micro_parameters.run_micro(replications));
+							}
+						}
+					});
+					if running {
+						ui.label("running...");
+					}
+					if let Some(result) = &self.micro_result {
+						Plot::new("micro")
+							.show_grid([false, false])
+							.height(200.0)
+							.show(ui, |ui| {
+								let mean: PlotPoints = result
+									.timeline
+									.total
+									.iter()
+									.map(|(year, band)| [year.0 as f64, band.mean])
+									.collect();
+								let low: PlotPoints = result
+									.timeline
+									.total
+									.iter()
+									.map(|(year, band)| [year.0 as f64, band.low])
+									.collect();
+								let high: PlotPoints = result
+									.timeline
+									.total
+									.iter()
+									.map(|(year, band)| [year.0 as f64, band.high])
+									.collect();
+								ui.line(Line::new("micro_low", low).color(Color32::GRAY));
+								ui.line(Line::new("micro_high", high).color(Color32::GRAY));
+								ui.line(Line::new("micro_mean", mean).color(Color32::GREEN));
+							});
+					}
+				});
 				#[cfg(not(target_arch = "wasm32"))]
 				ui.group(|ui| {
 					ui.horizontal(|ui| {
@@ -1,6 +1,7 @@
 use app::MyApp;
 use eframe::egui;
 mod app;
+mod microsim;
 mod optimizer;
 mod simulator;
 
@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+
+use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde::Serialize;
+
+use crate::simulator::Age;
+use crate::simulator::Count;
+use crate::simulator::Gender;
+use crate::simulator::Parameters;
+use crate::simulator::Year;
+use crate::simulator::age_relative_frequency;
+use crate::simulator::birth_probability_one_year;
+use crate::simulator::death_probability_one_year;
+use crate::simulator::male_birth_bias;
+
+/// An individual tracked by the microsimulation engine, as opposed to the
+/// aggregate cohort counts [`Parameters::run`] operates on.
+#[derive(Debug, Clone, Copy)]
+struct Agent {
+	age: u8,
+	gender: Gender,
+}
+
+/// Upper bound on [`MicroParameters::initial_population`]: this engine
+/// tracks one [`Agent`] per person and rebuilds a same-sized `Vec` every
+/// year, so it can't scale to the cohort model's population range.
+pub const MAX_MICRO_POPULATION: Count = 200_000;
+
+/// Drives [`Parameters`] through an individual-level, stochastic engine
+/// instead of closed-form cohort arithmetic. A run is fully determined by
+/// `seed`, so it can be reproduced exactly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MicroParameters {
+	pub parameters: Parameters,
+	/// Agent-level population size, independent of and much smaller than
+	/// `parameters.initial_population`; clamped to `MAX_MICRO_POPULATION`.
+	pub initial_population: Count,
+	pub seed: u64,
+}
+
+/// Mean and min/max band across replications for a single year.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct ConfidenceBand {
+	pub mean: f64,
+	pub low: f64,
+	pub high: f64,
+}
+
+impl ConfidenceBand {
+	fn from_samples(samples: &[f64]) -> Self {
+		let n = samples.len() as f64;
+		let mean = samples.iter().sum::<f64>() / n;
+		let low = samples.iter().copied().fold(f64::INFINITY, f64::min);
+		let high = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+		Self { mean, low, high }
+	}
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MicroTimeline {
+	pub total: BTreeMap<Year, ConfidenceBand>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MicroSimulationResult {
+	pub n_replications: u32,
+	pub timeline: MicroTimeline,
+}
+
+impl MicroParameters {
+	/// Runs `n_replications` independent microsimulations from `seed`,
+	/// `seed + 1`, ... and aggregates the per-year population counts into a
+	/// [`MicroTimeline`] of means and confidence bands.
+	pub fn run_micro(self, n_replications: u32) -> MicroSimulationResult {
+		let initial_population = self.initial_population.min(MAX_MICRO_POPULATION);
+		let series: Vec<BTreeMap<Year, Count>> = (0..n_replications)
+			.map(|replication| {
+				let seed = self.seed.wrapping_add(u64::from(replication));
+				simulate_one(self.parameters, initial_population, seed)
+			})
+			.collect();
+
+		let mut timeline = MicroTimeline::default();
+		for year in 0..=self.parameters.n_years {
+			let year = Year(i32::from(year));
+			let samples: Vec<f64> = series.iter().map(|s| s[&year] as f64).collect();
+			timeline.total.insert(year, ConfidenceBand::from_samples(&samples));
+		}
+
+		MicroSimulationResult {
+			n_replications,
+			timeline,
+		}
+	}
+}
+
+/// Builds the initial cohort of agents from the same age distribution the
+/// deterministic engine uses, one agent per individual. Kept deterministic
+/// (no RNG draws) so all replications start identically and only the
+/// death/birth process differs between seeds.
+fn initial_agents(parameters: Parameters, initial_population: Count) -> Vec<Agent> {
+	let mut agents = Vec::with_capacity(initial_population as usize);
+	for age in 0..=parameters.max_age.0 {
+		let age = Age(age);
+		let rel_freq = age_relative_frequency(age, parameters.max_age);
+		let count_each_gender = ((rel_freq * (initial_population as f64)) * 0.5) as Count;
+		for _ in 0..count_each_gender {
+			agents.push(Agent {
+				age: age.0,
+				gender: Gender::Male,
+			});
+			agents.push(Agent {
+				age: age.0,
+				gender: Gender::Female,
+			});
+		}
+	}
+	agents
+}
+
+fn simulate_one(parameters: Parameters, initial_population: Count, seed: u64) -> BTreeMap<Year, Count> {
+	let mut rng = StdRng::seed_from_u64(seed);
+	let male_birth_bias = male_birth_bias(parameters);
+
+	let mut agents = initial_agents(parameters, initial_population);
+	let mut series = BTreeMap::new();
+	series.insert(Year(0), agents.len() as Count);
+
+	for year in 1..=parameters.n_years {
+		let mut next_generation = Vec::with_capacity(agents.len());
+		let mut newborns = Vec::new();
+		for agent in &agents {
+			let age = agent.age.saturating_add(1).min(parameters.max_age.0);
+			let death_probability =
+				death_probability_one_year(Age(age), agent.gender, parameters.infant_mortality_rate);
+			if rng.gen_bool(death_probability.clamp(0.0, 1.0)) {
+				continue;
+			}
+			if agent.gender == Gender::Female {
+				let birth_probability =
+					birth_probability_one_year(age, parameters.target_total_fertility_rate);
+				if rng.gen_bool(birth_probability.clamp(0.0, 1.0)) {
+					let gender = if rng.gen_bool(male_birth_bias) {
+						Gender::Male
+					} else {
+						Gender::Female
+					};
+					newborns.push(Agent { age: 0, gender });
+				}
+			}
+			next_generation.push(Agent {
+				age,
+				gender: agent.gender,
+			});
+		}
+		next_generation.extend(newborns);
+		agents = next_generation;
+		series.insert(Year(i32::from(year)), agents.len() as Count);
+	}
+
+	series
+}
@@ -3,50 +3,289 @@ use argmin::core::Error;
 use argmin::core::Executor;
 use argmin::core::OptimizationResult;
 use argmin::solver::neldermead::NelderMead;
+use rand::Rng;
 
+use crate::simulator::Age;
+use crate::simulator::Count;
 use crate::simulator::Parameters;
 use crate::simulator::SimulationResult;
 use crate::simulator::Year;
 
-impl CostFunction for Parameters {
+/// Which parameter the optimizer is allowed to move to stabilize the population.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lever {
+	TotalFertilityRate,
+	NetMigration,
+}
+
+/// Squared slope of the population curve between the halfway point and where it first
+/// drops to a third of the initial population. Zero for a stabilized population.
+fn stable_slope_cost(parameters: Parameters) -> f64 {
+	let SimulationResult { timeline, .. } = parameters.run();
+	let first_year = timeline.first_key_value().unwrap().0;
+	let (end_year, &end_data) = timeline
+		.iter()
+		.find(|(_year, data)| data.sum() <= parameters.initial_population / 3)
+		.or_else(|| timeline.last_key_value())
+		.unwrap();
+	let halfway_year = Year((end_year.0 - first_year.0) / 2);
+	let halfway_data = timeline[&halfway_year];
+	let years = end_year.0 - halfway_year.0;
+	let end_sum = end_data.sum() as f64;
+	let halfway_sum = halfway_data.sum() as f64;
+	let difference = end_sum - halfway_sum;
+	let slope = difference / years as f64;
+	slope * slope
+}
+
+struct LeverObjective {
+	parameters: Parameters,
+	lever: Lever,
+}
+
+impl CostFunction for LeverObjective {
 	type Param = f64;
 	type Output = f64;
 
 	fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
-		let mut p = *self;
-		p.target_total_fertility_rate = param.clamp(0.0, 3.0);
-		let SimulationResult { timeline, .. } = p.run();
-		let first_year = timeline.first_key_value().unwrap().0;
-		let (end_year, &end_data) = timeline
-			.iter()
-			.find(|(_year, data)| data.sum() <= p.initial_population / 3)
-			.or_else(|| timeline.last_key_value())
-			.unwrap();
-		let halfway_year = Year((end_year.0 - first_year.0) / 2);
-		let halfway_data = timeline[&halfway_year];
-		let years = end_year.0 - halfway_year.0;
-		let end_sum = end_data.sum() as f64;
-		let halfway_sum = halfway_data.sum() as f64;
-		let difference = end_sum - halfway_sum;
-		let slope = difference / years as f64;
-		// println!(
-		// 	"tfr={}, halfway_sum={halfway_sum}, end_sum={end_sum}, difference={difference}, years={years}, slope={slope:e}",
-		// 	p.target_total_fertility_rate
-		// );
-		Ok(slope * slope)
+		let mut p = self.parameters;
+		match self.lever {
+			Lever::TotalFertilityRate => p.target_total_fertility_rate = param.clamp(0.0, 3.0),
+			Lever::NetMigration => p.net_migration_per_year = param.round() as i64,
+		}
+		Ok(stable_slope_cost(p))
 	}
 }
 
 pub fn solve(parameters: Parameters) -> Parameters {
-	let tfr = parameters.target_total_fertility_rate;
-	let solver = NelderMead::new(vec![tfr - 0.05, tfr + 0.05]);
-	let res = Executor::new(parameters, solver)
+	solve_with_lever(parameters, Lever::TotalFertilityRate)
+}
+
+pub fn solve_with_lever(parameters: Parameters, lever: Lever) -> Parameters {
+	let (param0, step) = match lever {
+		Lever::TotalFertilityRate => (parameters.target_total_fertility_rate, 0.05),
+		Lever::NetMigration => (parameters.net_migration_per_year as f64, 1000.0),
+	};
+	let solver = NelderMead::new(vec![param0 - step, param0 + step]);
+	let objective = LeverObjective { parameters, lever };
+	let res = Executor::new(objective, solver)
 		.configure(|state| state.max_iters(10_000))
 		.run()
 		.unwrap();
 	let OptimizationResult { state, .. } = res;
-	let target_tfr = state.best_param.unwrap();
+	let target = state.best_param.unwrap();
 	let mut parameters = parameters;
-	parameters.target_total_fertility_rate = target_tfr;
+	match lever {
+		Lever::TotalFertilityRate => parameters.target_total_fertility_rate = target,
+		Lever::NetMigration => parameters.net_migration_per_year = target.round() as i64,
+	}
+	parameters
+}
+
+/// What [`calibrate`] is trying to achieve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CalibrationObjective {
+	/// Same stable-slope criterion [`solve_with_lever`] uses.
+	StableSlope,
+	/// Distance to `target_final_population`, plus how much the age distribution shape drifted.
+	TargetPopulation { target_final_population: Count },
+}
+
+/// Large but finite, so a NaN/Inf cost doesn't poison the sort below.
+const UNSCORABLE_COST: f64 = 1e18;
+
+fn target_population_cost(parameters: Parameters, target_final_population: Count) -> f64 {
+	if target_final_population == 0 {
+		return UNSCORABLE_COST;
+	}
+	let SimulationResult {
+		initial_population,
+		final_population,
+		..
+	} = parameters.run();
+	let final_total = final_population.count() as f64;
+	let initial_total = initial_population.count() as f64;
+	if final_total <= 0.0 || initial_total <= 0.0 {
+		return UNSCORABLE_COST;
+	}
+	let size_error = (final_total - target_final_population as f64) / target_final_population as f64;
+
+	let shape_error: f64 = (0..=parameters.max_age.0)
+		.map(|age| {
+			let age = Age(age);
+			let initial_share = initial_population.count_age(age) as f64 / initial_total;
+			let final_share = final_population.count_age(age) as f64 / final_total;
+			(final_share - initial_share).powi(2)
+		})
+		.sum();
+
+	size_error * size_error + shape_error
+}
+
+fn calibration_cost(parameters: Parameters, objective: CalibrationObjective) -> f64 {
+	match objective {
+		CalibrationObjective::StableSlope => stable_slope_cost(parameters),
+		CalibrationObjective::TargetPopulation {
+			target_final_population,
+		} => target_population_cost(parameters, target_final_population),
+	}
+}
+
+/// Which [`Parameters`] fields the GA calibrator moves, bounded to `app.rs`'s slider ranges.
+const GENE_COUNT: usize = 4;
+const GENE_BOUNDS: [(f64, f64); GENE_COUNT] = [
+	(0.0, 3.0),             // target_total_fertility_rate
+	(80.0, 120.0),          // males_per_100_females
+	(0.001, 0.020),         // infant_mortality_rate
+	(-1_000_000.0, 1_000_000.0), // net_migration_per_year
+];
+
+type Genes = [f64; GENE_COUNT];
+
+fn genes_from_parameters(parameters: Parameters) -> Genes {
+	[
+		parameters.target_total_fertility_rate,
+		f64::from(parameters.males_per_100_females),
+		parameters.infant_mortality_rate,
+		parameters.net_migration_per_year as f64,
+	]
+}
+
+fn parameters_from_genes(base: Parameters, genes: Genes) -> Parameters {
+	let mut parameters = base;
+	parameters.target_total_fertility_rate = genes[0].clamp(GENE_BOUNDS[0].0, GENE_BOUNDS[0].1);
+	parameters.males_per_100_females =
+		genes[1].clamp(GENE_BOUNDS[1].0, GENE_BOUNDS[1].1).round() as u8;
+	parameters.infant_mortality_rate = genes[2].clamp(GENE_BOUNDS[2].0, GENE_BOUNDS[2].1);
+	parameters.net_migration_per_year =
+		genes[3].clamp(GENE_BOUNDS[3].0, GENE_BOUNDS[3].1).round() as i64;
 	parameters
 }
+
+/// Box-Muller transform, so [`mutate`] doesn't need an extra distribution crate beyond `rand`.
+fn standard_normal(rng: &mut impl Rng) -> f64 {
+	let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+	let u2: f64 = rng.gen_range(0.0..1.0);
+	(-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+fn mutate(genes: &mut Genes, probability: f64, sigma: f64, rng: &mut impl Rng) {
+	for (gene, &(low, high)) in genes.iter_mut().zip(GENE_BOUNDS.iter()) {
+		if rng.gen_bool(probability) {
+			*gene = (*gene + standard_normal(rng) * sigma * (high - low)).clamp(low, high);
+		}
+	}
+}
+
+fn blend_crossover(parent_a: Genes, parent_b: Genes, rng: &mut impl Rng) -> Genes {
+	let mut child = [0.0; GENE_COUNT];
+	for i in 0..GENE_COUNT {
+		let alpha: f64 = rng.gen_range(0.0..=1.0);
+		child[i] = alpha * parent_a[i] + (1.0 - alpha) * parent_b[i];
+	}
+	child
+}
+
+fn tournament_select(scored: &[(Genes, f64)], tournament_size: usize, rng: &mut impl Rng) -> Genes {
+	let mut best = scored[rng.gen_range(0..scored.len())];
+	for _ in 1..tournament_size {
+		let candidate = scored[rng.gen_range(0..scored.len())];
+		if candidate.1 > best.1 {
+			best = candidate;
+		}
+	}
+	best.0
+}
+
+/// Tuning knobs for [`calibrate`]'s genetic algorithm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaSettings {
+	pub population_size: usize,
+	pub elite_count: usize,
+	pub tournament_size: usize,
+	pub mutation_probability: f64,
+	pub mutation_sigma: f64,
+	pub max_generations: u32,
+	/// Stop once the best fitness improves by less than this for `stall_generations` in a row.
+	pub stall_threshold: f64,
+	pub stall_generations: u32,
+}
+
+impl Default for GaSettings {
+	fn default() -> Self {
+		Self {
+			population_size: 60,
+			elite_count: 4,
+			tournament_size: 4,
+			mutation_probability: 0.1,
+			mutation_sigma: 0.1,
+			max_generations: 200,
+			stall_threshold: 1e-6,
+			stall_generations: 15,
+		}
+	}
+}
+
+/// Jointly calibrates fertility, sex ratio, infant mortality and net migration via a GA.
+pub fn calibrate(
+	parameters: Parameters,
+	objective: CalibrationObjective,
+	settings: GaSettings,
+) -> Parameters {
+	let mut rng = rand::thread_rng();
+	let seed_genes = genes_from_parameters(parameters);
+
+	let mut population: Vec<Genes> = vec![seed_genes];
+	for _ in 1..settings.population_size {
+		let mut genes = seed_genes;
+		mutate(&mut genes, 1.0, settings.mutation_sigma, &mut rng);
+		population.push(genes);
+	}
+
+	let mut best_genes = seed_genes;
+	let mut best_fitness = f64::NEG_INFINITY;
+	let mut stalled_generations = 0;
+
+	for _generation in 0..settings.max_generations {
+		let mut scored: Vec<(Genes, f64)> = population
+			.iter()
+			.map(|&genes| {
+				let cost = calibration_cost(parameters_from_genes(parameters, genes), objective);
+				(genes, -cost)
+			})
+			.collect();
+		scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+		let generation_best = scored[0];
+		if generation_best.1 > best_fitness {
+			if generation_best.1 - best_fitness < settings.stall_threshold {
+				stalled_generations += 1;
+			} else {
+				stalled_generations = 0;
+			}
+			best_fitness = generation_best.1;
+			best_genes = generation_best.0;
+		} else {
+			stalled_generations += 1;
+		}
+		if stalled_generations >= settings.stall_generations {
+			break;
+		}
+
+		let mut next_generation: Vec<Genes> = scored
+			.iter()
+			.take(settings.elite_count)
+			.map(|(genes, _)| *genes)
+			.collect();
+		while next_generation.len() < settings.population_size {
+			let parent_a = tournament_select(&scored, settings.tournament_size, &mut rng);
+			let parent_b = tournament_select(&scored, settings.tournament_size, &mut rng);
+			let mut child = blend_crossover(parent_a, parent_b, &mut rng);
+			mutate(&mut child, settings.mutation_probability, settings.mutation_sigma, &mut rng);
+			next_generation.push(child);
+		}
+		population = next_generation;
+	}
+
+	parameters_from_genes(parameters, best_genes)
+}
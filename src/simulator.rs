@@ -94,12 +94,23 @@ pub struct SimulationResult {
 	pub final_population: AgeGenderMap,
 	pub cohort_fertility: CohortFertility,
 	pub timeline: Timeline,
+	pub total_net_migration: i64,
+	/// Zeroed unless `parity_fertility` is set.
+	pub parity_distribution: ParityDistribution,
+	/// `None` unless `parity_fertility` is set and at least one first birth occurred.
+	pub mean_age_at_first_birth: Option<f64>,
+	pub life_table_male: LifeTable,
+	pub life_table_female: LifeTable,
+	pub life_expectancy_at_birth: f64,
+	/// Deaths per 1,000 population in the final simulated year.
+	pub crude_death_rate: f64,
 }
 
 #[derive(Serialize, Default)]
 pub struct Timeline {
 	pub males: BTreeMap<Year, Count>,
 	pub females: BTreeMap<Year, Count>,
+	pub net_migration: BTreeMap<Year, i64>,
 }
 
 impl Timeline {
@@ -107,6 +118,10 @@ impl Timeline {
 		self.males.insert(year, males);
 		self.females.insert(year, females);
 	}
+
+	pub fn insert_net_migration(&mut self, year: Year, net_migration: i64) {
+		self.net_migration.insert(year, net_migration);
+	}
 	pub fn sum(&self, year: Year) -> Count {
 		let (m, f) = self.get_mf(year);
 		m + f
@@ -132,6 +147,346 @@ impl Timeline {
 	}
 }
 
+/// Relative weight of each age for the migrant age distribution.
+pub type MigrantAgeProfile = fn(Age) -> f64;
+
+pub const fn default_migrant_age_profile(age: Age) -> f64 {
+	match age.0 {
+		0..=19 => 0.010,
+		20..=35 => 0.045,
+		36..=64 => 0.015,
+		_ => 0.002,
+	}
+}
+
+const fn birth_probability_one_year_nominal(age: u8) -> f64 {
+	match age {
+		15..=19 => 0.04,
+		20..=24 => 0.10,
+		25..=29 => 0.13,
+		30..=34 => 0.12,
+		35..=39 => 0.08,
+		40..=44 => 0.03,
+		45..=49 => 0.005,
+		_ => 0.0,
+	}
+}
+const TFR_NOMINAL: f64 = {
+	let mut s = 0.0;
+	let mut age = 0;
+	loop {
+		s += birth_probability_one_year_nominal(age);
+		if age == 255 {
+			break;
+		}
+		age += 1;
+	}
+	s
+};
+
+/// Annual birth probability for a woman of `age`, scaled to `target_tfr`.
+pub(crate) fn birth_probability_one_year(age: u8, target_tfr: f64) -> f64 {
+	birth_probability_one_year_nominal(age) * target_tfr / TFR_NOMINAL
+}
+
+/// Age/sex-specific mortality hazard rate `m_x`.
+pub(crate) fn mortality_hazard_rate(age: Age, gender: Gender, infant_mortality_rate: f64) -> f64 {
+	match gender {
+		Gender::Male => match age.0 {
+			0 => infant_mortality_rate,
+			1 => 0.00039,
+			2..=4 => 0.00020,
+			5..=9 => 0.00013,
+			10..=14 => 0.00010,
+			15..=19 => 0.00022,
+			20..=24 => 0.00074,
+			25..=29 => 0.00097,
+			30..=34 => 0.00107,
+			35..=39 => 0.00127,
+			40..=44 => 0.00174,
+			45..=49 => 0.00261,
+			50..=54 => 0.00422,
+			55..=59 => 0.00689,
+			60..=64 => 0.01135,
+			65..=69 => 0.01871,
+			70..=74 => 0.03066,
+			75..=79 => 0.05027,
+			80..=84 => 0.08096,
+			85..=89 => 0.13257,
+			90..=94 => 0.20755,
+			95..=99 => 0.31234,
+			100.. => 0.43622,
+		},
+		Gender::Female => match age.0 {
+			0 => infant_mortality_rate,
+			1 => 0.00030,
+			2..=4 => 0.00015,
+			5..=9 => 0.00010,
+			10..=14 => 0.00008,
+			15..=19 => 0.00018,
+			20..=24 => 0.00060,
+			25..=29 => 0.00080,
+			30..=34 => 0.00090,
+			35..=39 => 0.00110,
+			40..=44 => 0.00150,
+			45..=49 => 0.00220,
+			50..=54 => 0.00350,
+			55..=59 => 0.00570,
+			60..=64 => 0.00940,
+			65..=69 => 0.01550,
+			70..=74 => 0.02540,
+			75..=79 => 0.04160,
+			80..=84 => 0.06700,
+			85..=89 => 0.10970,
+			90..=94 => 0.17100,
+			95..=99 => 0.25500,
+			100.. => 0.36000,
+		},
+	}
+}
+
+/// Separation factor `a_x`: fraction of the year lived, on average, by those who die at `age`.
+pub(crate) fn separation_factor(age: Age) -> f64 {
+	if age.0 == 0 { 0.1 } else { 0.5 }
+}
+
+/// `q_x = 1 - exp(-m_x)`.
+pub(crate) fn death_probability_one_year(age: Age, gender: Gender, infant_mortality_rate: f64) -> f64 {
+	let m = mortality_hazard_rate(age, gender, infant_mortality_rate);
+	1.0 - (-m).exp()
+}
+
+/// One row (age `x`) of a period life table.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct LifeTableRow {
+	/// Survivors to exact age `x`, out of an initial radix of 100,000.
+	pub l_x: f64,
+	/// Deaths between ages `x` and `x + 1`.
+	pub d_x: f64,
+	/// Person-years lived between ages `x` and `x + 1`; for the open-ended
+	/// top age, all person-years lived at that age and above.
+	pub l_x_lower: f64,
+	/// Person-years remaining at age `x` and above.
+	pub t_x: f64,
+	/// Life expectancy at age `x`.
+	pub e_x: f64,
+}
+
+/// A full period life table for one sex, indexed by age.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LifeTable {
+	pub rows: BTreeMap<Age, LifeTableRow>,
+}
+
+impl LifeTable {
+	/// Life expectancy at birth, `e_0`.
+	pub fn life_expectancy_at_birth(&self) -> f64 {
+		self.rows.get(&Age(0)).map_or(0.0, |row| row.e_x)
+	}
+}
+
+const LIFE_TABLE_RADIX: f64 = 100_000.0;
+
+pub(crate) fn build_life_table(gender: Gender, max_age: Age, infant_mortality_rate: f64) -> LifeTable {
+	// Forward pass: l_x and d_x from the radix down to the top age.
+	let mut l_x = vec![0.0; max_age.0 as usize + 1];
+	let mut d_x = vec![0.0; max_age.0 as usize + 1];
+	l_x[0] = LIFE_TABLE_RADIX;
+	for age in 0..=max_age.0 {
+		let q_x = death_probability_one_year(Age(age), gender, infant_mortality_rate);
+		d_x[age as usize] = l_x[age as usize] * q_x;
+		if age < max_age.0 {
+			l_x[age as usize + 1] = l_x[age as usize] - d_x[age as usize];
+		}
+	}
+
+	// Backward pass: L_x, T_x and e_x. The open-ended top age is a "plus
+	// group" (mirroring `propagate_age`'s accumulation there), so its L_x
+	// uses the stationary-population identity L_max = l_max / m_max instead
+	// of referencing a nonexistent l_{max+1}.
+	let mut rows = BTreeMap::new();
+	let mut t_x = 0.0;
+	for age in (0..=max_age.0).rev() {
+		let a_x = separation_factor(Age(age));
+		let l_x_lower = if age == max_age.0 {
+			let m_x = mortality_hazard_rate(Age(age), gender, infant_mortality_rate);
+			if m_x > 0.0 {
+				l_x[age as usize] / m_x
+			} else {
+				0.0
+			}
+		} else {
+			l_x[age as usize + 1] + a_x * d_x[age as usize]
+		};
+		t_x += l_x_lower;
+		let e_x = if l_x[age as usize] > 0.0 {
+			t_x / l_x[age as usize]
+		} else {
+			0.0
+		};
+		rows.insert(
+			Age(age),
+			LifeTableRow {
+				l_x: l_x[age as usize],
+				d_x: d_x[age as usize],
+				l_x_lower,
+				t_x,
+				e_x,
+			},
+		);
+	}
+
+	LifeTable { rows }
+}
+
+pub(crate) fn male_birth_bias(parameters: Parameters) -> f64 {
+	parameters.males_per_100_females as f64 / (parameters.males_per_100_females as f64 + 100.0)
+}
+
+pub(crate) fn age_relative_frequency(age: Age, max_age: Age) -> f64 {
+	if age > max_age {
+		return 0.0;
+	}
+	match age.0 {
+		0..=14 => 0.25 / 15.0,  // ~1.67% per year
+		15..=24 => 0.16 / 10.0, // 1.6% per year
+		25..=54 => 0.41 / 30.0, // ~1.37% per year
+		55..=64 => 0.09 / 10.0, // 0.9% per year
+		65.. => 0.09 / 56.0,    // ~0.16% per year
+	}
+}
+
+/// Number of previous live births a woman has had, bucketed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
+pub enum Parity {
+	P0,
+	P1,
+	P2,
+	P3Plus,
+}
+
+impl Parity {
+	fn next(self) -> Self {
+		match self {
+			Parity::P0 => Parity::P1,
+			Parity::P1 => Parity::P2,
+			Parity::P2 | Parity::P3Plus => Parity::P3Plus,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct ParityDistribution {
+	pub p0: Count,
+	pub p1: Count,
+	pub p2: Count,
+	pub p3_plus: Count,
+}
+
+impl ParityDistribution {
+	fn add(&mut self, parity: Parity, count: Count) {
+		match parity {
+			Parity::P0 => self.p0 += count,
+			Parity::P1 => self.p1 += count,
+			Parity::P2 => self.p2 += count,
+			Parity::P3Plus => self.p3_plus += count,
+		}
+	}
+}
+
+/// Years since a woman's previous live birth, capped at `YEARS_SINCE_BIRTH_CAP`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct YearsSinceBirth(u8);
+
+const YEARS_SINCE_BIRTH_CAP: u8 = 12;
+
+impl YearsSinceBirth {
+	fn incremented(self) -> Self {
+		Self((self.0 + 1).min(YEARS_SINCE_BIRTH_CAP))
+	}
+}
+
+/// Enables the parity-structured fertility model in [`Parameters::run`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParityFertilityParameters {
+	/// Target lifetime probability of never giving birth; see [`solve_first_birth_scale`].
+	pub childlessness_fraction: f64,
+	/// Rescale aggregate annual births to match `target_total_fertility_rate`.
+	pub align_to_target_tfr: bool,
+}
+
+const fn first_birth_rate_nominal(age: u8) -> f64 {
+	match age {
+		15..=19 => 0.03,
+		20..=24 => 0.09,
+		25..=29 => 0.08,
+		30..=34 => 0.05,
+		35..=39 => 0.02,
+		40..=44 => 0.005,
+		_ => 0.0,
+	}
+}
+
+const FIRST_BIRTH_AGE_RANGE: std::ops::RangeInclusive<u8> = 15..=44;
+
+/// Product of `(1 - scaled_hazard)` over [`FIRST_BIRTH_AGE_RANGE`].
+fn childless_survival(scale: f64) -> f64 {
+	FIRST_BIRTH_AGE_RANGE
+		.map(|age| 1.0 - (first_birth_rate_nominal(age) * scale).min(1.0))
+		.product()
+}
+
+/// Bisects for the scale where `childless_survival(scale) == childlessness_fraction`.
+fn solve_first_birth_scale(childlessness_fraction: f64) -> f64 {
+	let childlessness_fraction = childlessness_fraction.clamp(0.0, 1.0);
+	let max_rate = FIRST_BIRTH_AGE_RANGE
+		.map(first_birth_rate_nominal)
+		.fold(0.0_f64, f64::max);
+	if max_rate <= 0.0 {
+		return 0.0;
+	}
+	let (mut low, mut high) = (0.0, 1.0 / max_rate);
+	for _ in 0..60 {
+		let mid = (low + high) / 2.0;
+		if childless_survival(mid) > childlessness_fraction {
+			low = mid;
+		} else {
+			high = mid;
+		}
+	}
+	(low + high) / 2.0
+}
+
+fn first_birth_probability(age: u8, scale: f64) -> f64 {
+	(first_birth_rate_nominal(age) * scale).min(1.0)
+}
+
+fn higher_order_birth_probability(age: u8, parity: Parity, years_since_birth: u8) -> f64 {
+	let age_factor = match age {
+		15..=19 => 0.6,
+		20..=24 => 1.0,
+		25..=29 => 1.1,
+		30..=34 => 1.0,
+		35..=39 => 0.6,
+		40..=44 => 0.2,
+		_ => 0.0,
+	};
+	let duration_factor = match years_since_birth {
+		0..=1 => 0.3,
+		2..=3 => 1.0,
+		4..=5 => 0.6,
+		6..=9 => 0.3,
+		_ => 0.1,
+	};
+	let parity_factor = match parity {
+		Parity::P0 => 0.0,
+		Parity::P1 => 0.35,
+		Parity::P2 => 0.15,
+		Parity::P3Plus => 0.05,
+	};
+	age_factor * duration_factor * parity_factor * 0.1
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Parameters {
 	pub initial_population: Count,
@@ -140,6 +495,9 @@ pub struct Parameters {
 	pub males_per_100_females: u8,
 	pub target_total_fertility_rate: f64,
 	pub infant_mortality_rate: f64,
+	pub net_migration_per_year: i64,
+	pub migrant_age_profile: MigrantAgeProfile,
+	pub parity_fertility: Option<ParityFertilityParameters>,
 }
 
 impl Parameters {
@@ -154,11 +512,19 @@ impl Parameters {
 			population.map.count_gender(Gender::Female),
 		);
 
+		let mut total_net_migration: i64 = 0;
+		let mut last_year_exposure: Count = 0;
+		let mut last_year_deaths: Count = 0;
 		for year in 0..self.n_years {
 			let year = Year(initial_year.0 + i32::from(year));
 			population.propagate_age();
 			population.handle_births(year);
-			population.handle_deaths();
+			last_year_exposure =
+				population.map.count_gender(Gender::Male) + population.map.count_gender(Gender::Female);
+			last_year_deaths = population.handle_deaths();
+			let net_migration = population.handle_migration();
+			total_net_migration += net_migration;
+			timeline.insert_net_migration(year, net_migration);
 			timeline.insert(
 				Year(year.0 + 1),
 				population.map.count_gender(Gender::Male),
@@ -166,6 +532,16 @@ impl Parameters {
 			);
 		}
 
+		let mut parity_distribution = ParityDistribution::default();
+		for (&(_age, parity, _years_since), &count) in &population.female_parity {
+			parity_distribution.add(parity, count);
+		}
+		let mean_age_at_first_birth = if population.first_births_total > 0 {
+			Some(population.first_births_age_sum as f64 / population.first_births_total as f64)
+		} else {
+			None
+		};
+
 		let final_population = population.map;
 
 		let mut cohort_fertility = population.cohort_fertility;
@@ -173,11 +549,30 @@ impl Parameters {
 			year.0 >= initial_year.0 + 100 && year.0 <= (initial_year.0 + self.n_years as i32 - 100)
 		});
 
+		let life_table_male = build_life_table(Gender::Male, self.max_age, self.infant_mortality_rate);
+		let life_table_female =
+			build_life_table(Gender::Female, self.max_age, self.infant_mortality_rate);
+		let male_birth_bias = male_birth_bias(self);
+		let life_expectancy_at_birth = male_birth_bias * life_table_male.life_expectancy_at_birth()
+			+ (1.0 - male_birth_bias) * life_table_female.life_expectancy_at_birth();
+		let crude_death_rate = if last_year_exposure > 0 {
+			last_year_deaths as f64 / last_year_exposure as f64 * 1000.0
+		} else {
+			0.0
+		};
+
 		SimulationResult {
 			initial_population,
 			final_population,
 			cohort_fertility,
 			timeline,
+			total_net_migration,
+			parity_distribution,
+			mean_age_at_first_birth,
+			life_table_male,
+			life_table_female,
+			life_expectancy_at_birth,
+			crude_death_rate,
 		}
 	}
 }
@@ -187,23 +582,21 @@ struct PopulationSimulator {
 	cohort_fertility: CohortFertility,
 	parameters: Parameters,
 	male_birth_bias: f64,
+	/// Empty unless `parameters.parity_fertility` is set. Its per-age totals are
+	/// reset to `map.females` in `handle_deaths`; `handle_migration` changes
+	/// `map.females` only, so between that step and the next `handle_deaths`
+	/// (and in the final `parity_distribution`) these counts do not include
+	/// that year's female net migration.
+	female_parity: HashMap<(Age, Parity, YearsSinceBirth), Count>,
+	/// Solved once from `parameters.parity_fertility.childlessness_fraction`.
+	first_birth_scale: f64,
+	first_births_total: Count,
+	first_births_age_sum: u64,
 }
 
 impl PopulationSimulator {
 	fn new(parameters: Parameters) -> Self {
-		fn age_relative_frequency(age: Age, max_age: Age) -> f64 {
-			if age > max_age {
-				return 0.0;
-			}
-			match age.0 {
-				0..=14 => 0.25 / 15.0,  // ~1.67% per year
-				15..=24 => 0.16 / 10.0, // 1.6% per year
-				25..=54 => 0.41 / 30.0, // ~1.37% per year
-				55..=64 => 0.09 / 10.0, // 0.9% per year
-				65.. => 0.09 / 56.0,    // ~0.16% per year
-			}
-		}
-		let map: HashMap<_, _> = (0..=(parameters.max_age.0 + 1))
+		let map: HashMap<_, _> = (0..=parameters.max_age.0)
 			.map(|age| {
 				let age = Age(age);
 				let rel_freq = age_relative_frequency(age, parameters.max_age);
@@ -216,44 +609,41 @@ impl PopulationSimulator {
 			males: map.clone(),
 			females: map,
 		};
+		// The initial population has no birth history, so every woman
+		// starts out parity-0; the parity model only shapes behavior from
+		// here forward.
+		let female_parity = if parameters.parity_fertility.is_some() {
+			map.females
+				.iter()
+				.map(|(&age, &count)| ((age, Parity::P0, YearsSinceBirth(0)), count))
+				.collect()
+		} else {
+			HashMap::new()
+		};
+		let first_birth_scale = parameters
+			.parity_fertility
+			.map(|pf| solve_first_birth_scale(pf.childlessness_fraction))
+			.unwrap_or(0.0);
 		Self {
 			cohort_fertility: CohortFertility::default(),
 			map,
 			parameters,
-			male_birth_bias: parameters.males_per_100_females as f64
-				/ (parameters.males_per_100_females + 100) as f64,
+			male_birth_bias: male_birth_bias(parameters),
+			female_parity,
+			first_birth_scale,
+			first_births_total: 0,
+			first_births_age_sum: 0,
 		}
 	}
 
 	fn handle_births(&mut self, year: Year) {
-		const fn birth_probability_one_year_nominal(age: u8) -> f64 {
-			match age {
-				15..=19 => 0.04,
-				20..=24 => 0.10,
-				25..=29 => 0.13,
-				30..=34 => 0.12,
-				35..=39 => 0.08,
-				40..=44 => 0.03,
-				45..=49 => 0.005,
-				_ => 0.0,
-			}
-		}
-		const TFR_NOMINAL: f64 = {
-			let mut s = 0.0;
-			let mut age = 0;
-			loop {
-				s += birth_probability_one_year_nominal(age);
-				if age == 255 {
-					break;
-				}
-				age += 1;
-			}
-			s
-		};
-		fn birth_probability_one_year(age: u8, target_tfr: f64) -> f64 {
-			birth_probability_one_year_nominal(age) * target_tfr / TFR_NOMINAL
+		match self.parameters.parity_fertility {
+			None => self.handle_births_age_only(year),
+			Some(parity_fertility) => self.handle_births_by_parity(year, parity_fertility),
 		}
+	}
 
+	fn handle_births_age_only(&mut self, year: Year) {
 		let newborns = self
 			.map
 			.females
@@ -279,103 +669,272 @@ impl PopulationSimulator {
 			})
 			.map(|(_age, births)| births)
 			.sum::<Count>();
+		self.add_newborns(year, newborns);
+	}
+
+	fn add_newborns(&mut self, year: Year, newborns: Count) {
 		let males = (newborns as f64 * self.male_birth_bias).round() as Count;
 		let females = newborns - males;
 		let cf = self.cohort_fertility.0.entry(year).or_default();
 		cf.females += females;
 		*self.map.females.get_mut(&Age(0)).unwrap() += females;
 		*self.map.males.get_mut(&Age(0)).unwrap() += males;
+		if self.parameters.parity_fertility.is_some() {
+			*self
+				.female_parity
+				.entry((Age(0), Parity::P0, YearsSinceBirth(0)))
+				.or_default() += females;
+		}
+	}
+
+	/// Mirrors `map`'s own age shift, including [`Self::propagate_age`]'s plus-group entrant rescale.
+	fn propagate_parity(&mut self) {
+		let max_age = self.parameters.max_age.0;
+		let infant_mortality_rate = self.parameters.infant_mortality_rate;
+		let entrant_survival =
+			1.0 - death_probability_one_year(Age(max_age - 1), Gender::Female, infant_mortality_rate);
+		let plus_survival =
+			1.0 - death_probability_one_year(Age(max_age), Gender::Female, infant_mortality_rate);
+		let rescale = if plus_survival > 0.0 {
+			entrant_survival / plus_survival
+		} else {
+			0.0
+		};
+		let mut next = HashMap::with_capacity(self.female_parity.len());
+		for (&(age, parity, years_since), &count) in &self.female_parity {
+			let count = if age.0 + 1 == max_age {
+				(count as f64 * rescale).round() as Count
+			} else {
+				count
+			};
+			let new_age = Age((age.0 + 1).min(max_age));
+			let new_years_since = if parity == Parity::P0 {
+				YearsSinceBirth(0)
+			} else {
+				years_since.incremented()
+			};
+			*next.entry((new_age, parity, new_years_since)).or_default() += count;
+		}
+		self.female_parity = next;
 	}
 
-	fn handle_deaths(&mut self) {
-		fn death_probability_one_year(
-			age: Age,
-			gender: Gender,
-			max_age: Age,
-			infant_mortality_rate: f64,
-		) -> f64 {
-			if age >= max_age {
-				return 1.0;
+	fn handle_births_by_parity(&mut self, year: Year, parity_fertility: ParityFertilityParameters) {
+		self.propagate_parity();
+
+		let mut total_newborns: Count = 0;
+		let mut first_births_by_age: HashMap<Age, Count> = HashMap::new();
+		let mut transitions: Vec<((Age, Parity, YearsSinceBirth), Count)> = Vec::new();
+
+		for (&(age, parity, years_since), &women) in &self.female_parity {
+			let births = match parity {
+				Parity::P0 => {
+					let p = first_birth_probability(age.0, self.first_birth_scale);
+					(women as f64 * p).round() as Count
+				}
+				Parity::P1 | Parity::P2 | Parity::P3Plus => {
+					let p = higher_order_birth_probability(age.0, parity, years_since.0);
+					(women as f64 * p).round() as Count
+				}
+			};
+			if births == 0 {
+				continue;
+			}
+			if parity == Parity::P0 {
+				*first_births_by_age.entry(age).or_default() += births;
+			}
+			transitions.push(((age, parity, years_since), births));
+			total_newborns += births;
+		}
+
+		let scale = if parity_fertility.align_to_target_tfr {
+			let target_newborns = self
+				.map
+				.females
+				.iter()
+				.map(|(&age, &females)| {
+					(birth_probability_one_year(age.0, self.parameters.target_total_fertility_rate)
+						* (females as f64))
+						.round()
+				})
+				.sum::<f64>();
+			if total_newborns > 0 {
+				target_newborns / total_newborns as f64
+			} else {
+				1.0
 			}
-			match gender {
-				Gender::Male => match age.0 {
-					0 => infant_mortality_rate,
-					1 => 0.00039,
-					2..=4 => 0.00020,
-					5..=9 => 0.00013,
-					10..=14 => 0.00010,
-					15..=19 => 0.00022,
-					20..=24 => 0.00074,
-					25..=29 => 0.00097,
-					30..=34 => 0.00107,
-					35..=39 => 0.00127,
-					40..=44 => 0.00174,
-					45..=49 => 0.00261,
-					50..=54 => 0.00422,
-					55..=59 => 0.00689,
-					60..=64 => 0.01135,
-					65..=69 => 0.01871,
-					70..=74 => 0.03066,
-					75..=79 => 0.05027,
-					80..=84 => 0.08096,
-					85..=89 => 0.13257,
-					90..=94 => 0.20755,
-					95..=99 => 0.31234,
-					100.. => 0.43622,
-				},
-				Gender::Female => match age.0 {
-					0 => infant_mortality_rate,
-					1 => 0.00030,
-					2..=4 => 0.00015,
-					5..=9 => 0.00010,
-					10..=14 => 0.00008,
-					15..=19 => 0.00018,
-					20..=24 => 0.00060,
-					25..=29 => 0.00080,
-					30..=34 => 0.00090,
-					35..=39 => 0.00110,
-					40..=44 => 0.00150,
-					45..=49 => 0.00220,
-					50..=54 => 0.00350,
-					55..=59 => 0.00570,
-					60..=64 => 0.00940,
-					65..=69 => 0.01550,
-					70..=74 => 0.02540,
-					75..=79 => 0.04160,
-					80..=84 => 0.06700,
-					85..=89 => 0.10970,
-					90..=94 => 0.17100,
-					95..=99 => 0.25500,
-					100.. => 0.36000,
-				},
+		} else {
+			1.0
+		};
+
+		for ((age, parity, years_since), births) in transitions {
+			let moving = (births as f64 * scale).round() as Count;
+			if moving == 0 {
+				continue;
 			}
+			let bucket = self
+				.female_parity
+				.get_mut(&(age, parity, years_since))
+				.unwrap();
+			let moving = moving.min(*bucket);
+			*bucket -= moving;
+			*self
+				.female_parity
+				.entry((age, parity.next(), YearsSinceBirth(0)))
+				.or_default() += moving;
+
+			let mothers_birth_year = Year(year.0 - age.0 as i32);
+			let cf = self
+				.cohort_fertility
+				.0
+				.entry(mothers_birth_year)
+				.or_default();
+			cf.births += moving;
 		}
 
+		let scaled_newborns = (total_newborns as f64 * scale).round() as Count;
+		let scaled_first_births: Count = first_births_by_age
+			.values()
+			.map(|&births| (births as f64 * scale).round() as Count)
+			.sum();
+		let first_birth_age_sum: u64 = first_births_by_age
+			.iter()
+			.map(|(&age, &births)| u64::from(age.0) * (births as f64 * scale).round() as u64)
+			.sum();
+		self.first_births_total += scaled_first_births;
+		self.first_births_age_sum += first_birth_age_sum;
+
+		self.add_newborns(year, scaled_newborns);
+	}
+
+	/// Applies one year of mortality, returning the total number of deaths.
+	fn handle_deaths(&mut self) -> Count {
+		let mut total_deaths: Count = 0;
+
 		for (age, count) in &mut self.map.males {
-			let probability = death_probability_one_year(
-				*age,
-				Gender::Male,
-				self.parameters.max_age,
-				self.parameters.infant_mortality_rate,
-			);
+			let probability =
+				death_probability_one_year(*age, Gender::Male, self.parameters.infant_mortality_rate);
 			let deaths = (*count as f64 * probability).round();
 			*count -= deaths as Count;
+			total_deaths += deaths as Count;
+		}
+
+		// Grouped once up front instead of rescanning the whole map for
+		// every age below, which used to be an O(max_age * |female_parity|)
+		// nested scan.
+		let mut parity_keys_by_age: HashMap<Age, Vec<(Parity, YearsSinceBirth)>> = HashMap::new();
+		if self.parameters.parity_fertility.is_some() {
+			for &(age, parity, years_since) in self.female_parity.keys() {
+				parity_keys_by_age
+					.entry(age)
+					.or_default()
+					.push((parity, years_since));
+			}
 		}
 
 		for (age, count) in &mut self.map.females {
 			let probability = death_probability_one_year(
 				*age,
 				Gender::Female,
-				self.parameters.max_age,
 				self.parameters.infant_mortality_rate,
 			);
-			let deaths = (*count as f64 * probability).round();
-			*count -= deaths as Count;
+			let old_count = *count;
+			let deaths = (old_count as f64 * probability).round() as Count;
+			*count -= deaths;
+			total_deaths += deaths;
+
+			// Rescale `female_parity`'s buckets for this age to the exact
+			// post-mortality headcount, `*count`, rather than rounding
+			// each bucket independently: that used to let
+			// `sum(female_parity for age)` drift away from
+			// `map.females[age]` over a multi-thousand-year run. Every
+			// bucket but the last is rounded normally; the last absorbs
+			// whatever's left so the two stay in lockstep exactly.
+			if old_count > 0 {
+				if let Some(keys) = parity_keys_by_age.get(age) {
+					let new_total = *count;
+					let survival = new_total as f64 / old_count as f64;
+					let mut allocated: Count = 0;
+					if let [init @ .., last] = keys.as_slice() {
+						for &(parity, years_since) in init {
+							let women = self
+								.female_parity
+								.get_mut(&(*age, parity, years_since))
+								.unwrap();
+							let survivors =
+								((*women as f64 * survival).round() as Count).min(new_total - allocated);
+							allocated += survivors;
+							*women = survivors;
+						}
+						let (last_parity, last_years_since) = *last;
+						*self
+							.female_parity
+							.get_mut(&(*age, last_parity, last_years_since))
+							.unwrap() = new_total - allocated;
+					}
+				}
+			}
+		}
+
+		total_deaths
+	}
+
+	/// Returns the net migration actually applied, after clamping each bucket at zero.
+	fn handle_migration(&mut self) -> i64 {
+		fn apply(count: &mut Count, delta: i64) -> i64 {
+			if delta >= 0 {
+				*count += delta as Count;
+				delta
+			} else {
+				let before = *count;
+				*count = count.saturating_sub((-delta) as Count);
+				-((before - *count) as i64)
+			}
+		}
+
+		let net = self.parameters.net_migration_per_year;
+		if net == 0 {
+			return 0;
+		}
+		let max_age = self.parameters.max_age;
+		let profile = self.parameters.migrant_age_profile;
+		let total_weight: f64 = (0..=max_age.0).map(|age| profile(Age(age))).sum();
+		if total_weight <= 0.0 {
+			return 0;
 		}
+		let net_males = (net as f64 * self.male_birth_bias).round() as i64;
+		let net_females = net - net_males;
+
+		let mut applied = 0;
+		for age in 0..=max_age.0 {
+			let age = Age(age);
+			let share = profile(age) / total_weight;
+			let male_delta = (net_males as f64 * share).round() as i64;
+			let female_delta = (net_females as f64 * share).round() as i64;
+			applied += apply(self.map.males.get_mut(&age).unwrap(), male_delta);
+			applied += apply(self.map.females.get_mut(&age).unwrap(), female_delta);
+		}
+		applied
 	}
 
+	/// Shifts everyone up one age; `max_age` is an open-ended "plus group" that accumulates.
 	fn propagate_age(&mut self) {
-		for age in (0..=self.parameters.max_age.0).rev() {
+		let max_age = self.parameters.max_age.0;
+		let infant_mortality_rate = self.parameters.infant_mortality_rate;
+		// `handle_deaths` applies a single `S(max_age)` to the whole plus group, so the
+		// entrant from `max_age - 1` is rescaled by `S(max_age - 1) / S(max_age)` here to
+		// get its own survival probability instead of a second helping of `S(max_age)`.
+		let rescale_entrant = |young: Count, gender: Gender| -> Count {
+			let entrant_survival =
+				1.0 - death_probability_one_year(Age(max_age - 1), gender, infant_mortality_rate);
+			let plus_survival =
+				1.0 - death_probability_one_year(Age(max_age), gender, infant_mortality_rate);
+			if plus_survival > 0.0 {
+				(young as f64 * entrant_survival / plus_survival).round() as Count
+			} else {
+				0
+			}
+		};
+		for age in (0..max_age).rev() {
 			let [old_male, young_male] = self
 				.map
 				.males
@@ -386,9 +945,14 @@ impl PopulationSimulator {
 				.females
 				.get_disjoint_mut([&Age(age + 1), &Age(age)])
 				.map(Option::unwrap);
-			*old_male = *young_male;
+			if age + 1 == max_age {
+				*old_male += rescale_entrant(*young_male, Gender::Male);
+				*old_female += rescale_entrant(*young_female, Gender::Female);
+			} else {
+				*old_male = *young_male;
+				*old_female = *young_female;
+			}
 			*young_male = 0;
-			*old_female = *young_female;
 			*young_female = 0;
 		}
 	}